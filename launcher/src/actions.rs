@@ -1,18 +1,95 @@
-use std::process::Command;
+use std::collections::HashMap;
+use std::process::{Child, Command};
+use std::time::Duration;
+
 use crate::models::{App, LauncherState};
 
-pub fn launch_app(state: &LauncherState, app_id: &str) {
-    if let Some(app) = state.apps.get(app_id) {
-        println!("🚀 Launching app: {}", app.name);
+/// Tracks sandboxed apps that are currently running, keyed by app id, so the
+/// launcher can bring an already-open app to front instead of spawning a
+/// duplicate, render a "running" badge, and cycle through them in the
+/// task-switcher.
+#[derive(Default)]
+pub struct RunningApps(HashMap<String, Child>);
+
+impl RunningApps {
+    pub fn is_running(&self, app_id: &str) -> bool {
+        self.0.contains_key(app_id)
+    }
+
+    pub fn running_ids(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+
+    fn insert(&mut self, app_id: String, child: Child) {
+        self.0.insert(app_id, child);
+    }
+
+    /// Sends SIGTERM to the app's child process and waits for it to exit,
+    /// but only up to `CLOSE_POLL_ATTEMPTS * CLOSE_POLL_INTERVAL`. This runs
+    /// on the UI/event-loop thread, so it can't block indefinitely on
+    /// `Child::wait` — an app that ignores or
+    /// is slow to react to SIGTERM would otherwise freeze the whole Slint
+    /// event loop. If the child hasn't exited by the end of the bounded
+    /// poll, it's left in the map; the periodic `reap_exited` call in
+    /// `main`'s datetime timer picks up the exit whenever it actually lands.
+    pub fn close(&mut self, app_id: &str) {
+        const CLOSE_POLL_ATTEMPTS: u32 = 10;
+        const CLOSE_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+        let Some(child) = self.0.get_mut(app_id) else { return };
+        let _ = Command::new("kill")
+            .arg("-TERM")
+            .arg(child.id().to_string())
+            .status();
+
+        for _ in 0..CLOSE_POLL_ATTEMPTS {
+            match child.try_wait() {
+                Ok(Some(_)) => {
+                    self.0.remove(app_id);
+                    return;
+                }
+                Ok(None) => std::thread::sleep(CLOSE_POLL_INTERVAL),
+                Err(_) => return,
+            }
+        }
+    }
+
+    /// Drops the handle of any child that has already exited, so the
+    /// running set stays accurate even if an app was closed on its own.
+    pub fn reap_exited(&mut self) {
+        self.0.retain(|_, child| matches!(child.try_wait(), Ok(None)));
+    }
+}
 
+pub fn launch_app(state: &LauncherState, running: &mut RunningApps, app_id: &str) {
+    if let Some(app) = state.apps.get(app_id) {
         if app.executable_path.starts_with("builtin://") {
+            println!("🚀 Launching app: {}", app.name);
             handle_builtin_app(&app.executable_path);
-        } else {
-            launch_sandboxed_app(app);
+            return;
+        }
+
+        if running.is_running(app_id) {
+            // TODO: actually bring the app's surface to front. Doing that
+            // needs compositor support we don't have yet, so for now this
+            // is a no-op — make sure the log says that instead of claiming
+            // a switch happened.
+            println!("ℹ️ {} is already running; bringing it to front isn't supported yet", app.name);
+            return;
+        }
+
+        println!("🚀 Launching app: {}", app.name);
+        if let Some(child) = launch_sandboxed_app(app) {
+            running.insert(app_id.to_string(), child);
         }
     }
 }
 
+/// Sends SIGTERM to the running app's child process, if any.
+pub fn close_app(running: &mut RunningApps, app_id: &str) {
+    running.close(app_id);
+}
+
 fn handle_builtin_app(path: &str) {
     match path {
          "builtin://tv" => {
@@ -37,7 +114,9 @@ fn handle_builtin_app(path: &str) {
             }
             "builtin://app_store" => {
                 println!("🏪 Opening App Store...");
-                // TODO: Implement app store
+                // Installing/uninstalling apps is handled by the `install`
+                // module via the `on_install_app`/`on_uninstall_app`
+                // callbacks wired up in `main`; this branch only logs.
             }
             _ => {
                 println!("❓ Unknown builtin app: {}", path);
@@ -45,13 +124,17 @@ fn handle_builtin_app(path: &str) {
     }
 }
 
-fn launch_sandboxed_app(app: &App) {
-    let mut cmd = Command::new("unshare");
-    cmd.args(&["--net", "--pid", "--fork"])
-        .arg(&app.executable_path);
+fn launch_sandboxed_app(app: &App) -> Option<Child> {
+    let mut cmd = crate::sandbox::command_for(app);
 
     match cmd.spawn() {
-        Ok(child) => println!("✅ Launched {} (PID: {:?})", app.name, child.id()),
-        Err(e) => eprintln!("❌ Failed to launch {}: {}", app.name, e),
+        Ok(child) => {
+            println!("✅ Launched {} (PID: {:?})", app.name, child.id());
+            Some(child)
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to launch {}: {}", app.name, e);
+            None
+        }
     }
 }
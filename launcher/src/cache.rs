@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use slint::Image;
+
+use crate::models::App;
+
+fn mtime(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn mtime_secs(path: &Path) -> Option<u64> {
+    mtime(path)?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
+/// The directory the launcher stores its caches and downloaded assets in.
+/// `ui::get_user_icons_dir` lives under this same directory.
+pub fn user_data_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let app_data = if cfg!(target_os = "windows") {
+        std::env::var("APPDATA")?
+    } else if cfg!(target_os = "macos") {
+        format!("{}/Library/Application Support", std::env::var("HOME")?)
+    } else {
+        format!("{}/.local/share", std::env::var("HOME")?)
+    };
+
+    Ok(Path::new(&app_data).join("YourAppName"))
+}
+
+fn app_cache_path() -> Option<PathBuf> {
+    user_data_dir().ok().map(|dir| dir.join("app_cache.json"))
+}
+
+/// In-memory cache of decoded icon images keyed by their resolved file
+/// path. A lookup only re-decodes (including SVG rasterization) when the
+/// backing file's mtime has changed since the cached entry was produced.
+#[derive(Default)]
+pub struct IconCache {
+    entries: HashMap<String, (SystemTime, Image)>,
+}
+
+impl IconCache {
+    pub fn get_or_decode(&mut self, path: &str, decode: impl FnOnce(&str) -> Option<Image>) -> Option<Image> {
+        let current = mtime(Path::new(path));
+
+        if let Some(current) = current {
+            if let Some((cached_mtime, image)) = self.entries.get(path) {
+                if *cached_mtime == current {
+                    return Some(image.clone());
+                }
+            }
+        }
+
+        let image = decode(path)?;
+        if let Some(current) = current {
+            self.entries.insert(path.to_string(), (current, image.clone()));
+        }
+        Some(image)
+    }
+
+    /// Drops entries whose backing file has since disappeared.
+    pub fn evict_missing(&mut self) {
+        self.entries.retain(|path, _| Path::new(path).exists());
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct CachedApp {
+    mtime_secs: u64,
+    app: App,
+}
+
+/// Cache of parsed `app.json` metadata keyed by the app's directory,
+/// persisted to `user_data_dir()` so a cold start skips re-parsing an
+/// `app.json` whose mtime is unchanged. The apps directory itself is still
+/// listed by `loader::load_apps` on every load; only the parsing is skipped.
+#[derive(Default, Serialize, Deserialize)]
+pub struct AppCache {
+    entries: HashMap<String, CachedApp>,
+}
+
+impl AppCache {
+    pub fn load() -> Self {
+        app_cache_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let Some(path) = app_cache_path() else { return };
+        let Ok(json) = serde_json::to_string(self) else { return };
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = std::fs::write(path, json);
+    }
+
+    pub fn get_or_parse(&mut self, dir: &Path, parse: impl FnOnce(&Path) -> Option<App>) -> Option<App> {
+        let key = dir.to_string_lossy().to_string();
+        let current_secs = mtime_secs(&dir.join("app.json"));
+
+        if let Some(secs) = current_secs {
+            if let Some(cached) = self.entries.get(&key) {
+                if cached.mtime_secs == secs {
+                    return Some(cached.app.clone());
+                }
+            }
+        }
+
+        let app = parse(dir)?;
+        if let Some(secs) = current_secs {
+            self.entries.insert(
+                key,
+                CachedApp {
+                    mtime_secs: secs,
+                    app: app.clone(),
+                },
+            );
+        }
+        Some(app)
+    }
+
+    /// Drops entries for apps whose directory has been removed.
+    pub fn evict_missing(&mut self) {
+        self.entries.retain(|dir, _| Path::new(dir).join("app.json").exists());
+    }
+}
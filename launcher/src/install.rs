@@ -0,0 +1,239 @@
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::loader::update_app_lists;
+use crate::models::{App, LauncherState};
+
+/// An entry in the remote app catalog, describing a package that can be
+/// downloaded and installed but isn't necessarily present on disk yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub version: String,
+    pub category: String,
+    pub download_url: String,
+    pub size_bytes: u64,
+    pub sha256: String,
+}
+
+/// Bundles larger than this are rejected before any buffer is allocated for
+/// them. `size_bytes` comes straight from the remote catalog, so without a
+/// cap a bogus value could make `download_bundle` try to reserve an
+/// arbitrarily large `Vec` and abort the process via allocator OOM.
+const MAX_BUNDLE_SIZE_BYTES: u64 = 512 * 1024 * 1024;
+
+#[derive(Debug)]
+pub enum InstallError {
+    Network(String),
+    Catalog(String),
+    VerificationFailed,
+    BundleTooLarge(u64),
+    Io(std::io::Error),
+    NotFound(String),
+}
+
+impl std::fmt::Display for InstallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstallError::Network(msg) => write!(f, "network error: {}", msg),
+            InstallError::Catalog(msg) => write!(f, "catalog error: {}", msg),
+            InstallError::VerificationFailed => write!(f, "bundle checksum verification failed"),
+            InstallError::BundleTooLarge(size) => {
+                write!(f, "bundle size {} bytes exceeds the {} byte limit", size, MAX_BUNDLE_SIZE_BYTES)
+            }
+            InstallError::Io(e) => write!(f, "io error: {}", e),
+            InstallError::NotFound(id) => write!(f, "app not found in catalog: {}", id),
+        }
+    }
+}
+
+impl From<std::io::Error> for InstallError {
+    fn from(e: std::io::Error) -> Self {
+        InstallError::Io(e)
+    }
+}
+
+/// Fetches the remote catalog describing apps available for installation,
+/// independent of whatever is already present under `apps_directory`.
+pub fn fetch_catalog(catalog_url: &str) -> Result<Vec<CatalogEntry>, InstallError> {
+    let body = ureq::get(catalog_url)
+        .call()
+        .map_err(|e| InstallError::Network(e.to_string()))?
+        .into_string()
+        .map_err(|e| InstallError::Network(e.to_string()))?;
+
+    serde_json::from_str(&body).map_err(|e| InstallError::Catalog(e.to_string()))
+}
+
+/// Merges catalog entries into `state.apps` as not-yet-installed apps,
+/// without touching anything already discovered on the local filesystem.
+pub fn merge_catalog(state: &mut LauncherState, catalog: &[CatalogEntry]) {
+    for entry in catalog {
+        if state.apps.contains_key(&entry.id) {
+            continue;
+        }
+        state.apps.insert(
+            entry.id.clone(),
+            App {
+                id: entry.id.clone(),
+                name: entry.name.clone(),
+                description: entry.description.clone(),
+                icon_path: None,
+                executable_path: String::new(),
+                installed: false,
+                version: entry.version.clone(),
+                category: entry.category.clone(),
+                sandbox: None,
+            },
+        );
+    }
+    update_app_lists(state);
+}
+
+/// Downloads the bundle for `entry`, checks it against the catalog's
+/// `sha256`, extracts it into `apps_directory/<id>/`, and returns the
+/// installed app's metadata as read back from its `app.json`. Runs on the
+/// worker thread; the caller is responsible for folding the result into
+/// `LauncherState` and refreshing the app lists so the app moves from
+/// `store_apps` into `installed_apps`.
+///
+/// Note `sha256` is supplied by the same remote catalog as `download_url`,
+/// so this only catches transit corruption, not a malicious catalog — it is
+/// not an authenticity check. Treating the catalog endpoint itself as
+/// trusted (HTTPS + whatever access control protects it) is still load
+/// bearing; this function can't substitute for that.
+pub fn download_and_install(
+    apps_directory: &str,
+    entry: &CatalogEntry,
+    mut on_progress: impl FnMut(f32),
+) -> Result<App, InstallError> {
+    on_progress(0.0);
+    let bundle = download_bundle(&entry.download_url, entry.size_bytes, &mut on_progress)?;
+
+    if !verify_bundle(&bundle, &entry.sha256) {
+        return Err(InstallError::VerificationFailed);
+    }
+
+    let install_dir = PathBuf::from(apps_directory).join(&entry.id);
+    fs::create_dir_all(&install_dir)?;
+    extract_bundle(&bundle, &install_dir)?;
+    on_progress(1.0);
+
+    crate::loader::load_app_from_directory(&install_dir)
+        .ok_or_else(|| InstallError::Catalog(format!("{}: missing or invalid app.json after extraction", entry.id)))
+}
+
+/// Removes the installed app's directory and refreshes the app lists.
+pub fn uninstall_app(state: &mut LauncherState, app_id: &str) -> Result<(), InstallError> {
+    let install_dir = PathBuf::from(&state.apps_directory).join(app_id);
+    if install_dir.exists() {
+        fs::remove_dir_all(&install_dir)?;
+    }
+    state.apps.remove(app_id);
+    update_app_lists(state);
+    Ok(())
+}
+
+fn download_bundle(
+    url: &str,
+    size_bytes: u64,
+    on_progress: &mut impl FnMut(f32),
+) -> Result<Vec<u8>, InstallError> {
+    if size_bytes > MAX_BUNDLE_SIZE_BYTES {
+        return Err(InstallError::BundleTooLarge(size_bytes));
+    }
+
+    let response = ureq::get(url)
+        .call()
+        .map_err(|e| InstallError::Network(e.to_string()))?;
+
+    let mut reader = response.into_reader();
+    // `size_bytes` is already capped above, but the server is free to send
+    // more than it advertised, so the buffer still grows incrementally
+    // rather than trusting it as a hard pre-allocation.
+    let mut buf = Vec::with_capacity(size_bytes as usize);
+    let mut chunk = [0u8; 64 * 1024];
+    loop {
+        let n = reader.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        if buf.len() as u64 + n as u64 > MAX_BUNDLE_SIZE_BYTES {
+            return Err(InstallError::BundleTooLarge(buf.len() as u64 + n as u64));
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if size_bytes > 0 {
+            on_progress((buf.len() as f32 / size_bytes as f32).min(1.0));
+        }
+    }
+    Ok(buf)
+}
+
+/// Verifies the downloaded bundle's checksum against the catalog entry's
+/// `sha256` before it's ever extracted to disk. This is a transit-integrity
+/// check, not an authenticity check — see the note on `download_and_install`.
+fn verify_bundle(bundle: &[u8], expected_sha256: &str) -> bool {
+    sha256_hex(bundle).eq_ignore_ascii_case(expected_sha256)
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Extracts the bundle's tarball (app.json, executable, icon, background)
+/// into `install_dir`.
+fn extract_bundle(bundle: &[u8], install_dir: &PathBuf) -> Result<(), InstallError> {
+    let decoder = flate2::read::GzDecoder::new(bundle);
+    let mut archive = tar::Archive::new(decoder);
+    archive.unpack(install_dir)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_bundle_accepts_matching_checksum() {
+        let bundle = b"bundle contents";
+        assert!(verify_bundle(bundle, &sha256_hex(bundle)));
+    }
+
+    #[test]
+    fn verify_bundle_rejects_mismatched_checksum() {
+        let bundle = b"bundle contents";
+        let wrong = sha256_hex(b"different contents");
+        assert!(!verify_bundle(bundle, &wrong));
+    }
+
+    #[test]
+    fn verify_bundle_checksum_comparison_is_case_insensitive() {
+        let bundle = b"bundle contents";
+        assert!(verify_bundle(bundle, &sha256_hex(bundle).to_uppercase()));
+    }
+
+    #[test]
+    fn download_bundle_rejects_oversized_advertised_size_before_any_request() {
+        // size_bytes over the cap is rejected before download_bundle ever
+        // calls out, so a bogus URL proves this path never reaches the
+        // network.
+        let result = download_bundle(
+            "not-a-real-url",
+            MAX_BUNDLE_SIZE_BYTES + 1,
+            &mut |_| {},
+        );
+        assert!(matches!(result, Err(InstallError::BundleTooLarge(size)) if size == MAX_BUNDLE_SIZE_BYTES + 1));
+    }
+}
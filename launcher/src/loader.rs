@@ -1,23 +1,26 @@
 use std::fs;
 use std::path::Path;
+use crate::cache::AppCache;
 use crate::models::{App, LauncherState};
 
-pub fn load_apps(state: &mut LauncherState) {
-    load_installed_apps(state);
+pub fn load_apps(state: &mut LauncherState, cache: &mut AppCache) {
+    load_installed_apps(state, cache);
     add_builtin_apps(state);
     update_app_lists(state);
 }
 
-fn load_installed_apps(state: &mut LauncherState) {
+fn load_installed_apps(state: &mut LauncherState, cache: &mut AppCache) {
     if let Ok(entries) = fs::read_dir(&state.apps_directory) {
         for entry in entries.flatten() {
-            if entry.path().is_dir() {
-                if let Some(app) = load_app_from_directory(&entry.path()) {
+            let path = entry.path();
+            if path.is_dir() {
+                if let Some(app) = cache.get_or_parse(&path, load_app_from_directory) {
                     state.apps.insert(app.id.clone(), app);
                 }
             }
         }
     }
+    cache.evict_missing();
 }
 
 fn add_builtin_apps(state: &mut LauncherState) {
@@ -31,6 +34,7 @@ fn add_builtin_apps(state: &mut LauncherState) {
                 installed: true,
                 version: "1.0.0".to_string(),
                 category: "Entertainment".to_string(),
+                sandbox: None,
             },
             App {
                 id: "movies".to_string(),
@@ -41,6 +45,7 @@ fn add_builtin_apps(state: &mut LauncherState) {
                 installed: true,
                 version: "1.0.0".to_string(),
                 category: "Entertainment".to_string(),
+                sandbox: None,
             },
             App {
                 id: "music".to_string(),
@@ -51,6 +56,7 @@ fn add_builtin_apps(state: &mut LauncherState) {
                 installed: true,
                 version: "1.0.0".to_string(),
                 category: "Entertainment".to_string(),
+                sandbox: None,
             },
             App {
                 id: "photos".to_string(),
@@ -61,6 +67,7 @@ fn add_builtin_apps(state: &mut LauncherState) {
                 installed: true,
                 version: "1.0.0".to_string(),
                 category: "Media".to_string(),
+                sandbox: None,
             },
             App {
                 id: "settings".to_string(),
@@ -71,6 +78,7 @@ fn add_builtin_apps(state: &mut LauncherState) {
                 installed: true,
                 version: "1.0.0".to_string(),
                 category: "System".to_string(),
+                sandbox: None,
             },
             App {
                 id: "app_store".to_string(),
@@ -81,6 +89,7 @@ fn add_builtin_apps(state: &mut LauncherState) {
                 installed: true,
                 version: "1.0.0".to_string(),
                 category: "System".to_string(),
+                sandbox: None,
             },
     ];
     for app in builtin_apps {
@@ -88,7 +97,7 @@ fn add_builtin_apps(state: &mut LauncherState) {
     }
 }
 
-fn load_app_from_directory(path: &Path) -> Option<App> {
+pub(crate) fn load_app_from_directory(path: &Path) -> Option<App> {
     let metadata_path = path.join("app.json");
     if metadata_path.exists() {
         if let Ok(content) = fs::read_to_string(metadata_path) {
@@ -3,15 +3,21 @@ mod loader;
 mod actions;
 mod ui;
 mod navigation;
+mod install;
+mod worker;
+mod search;
+mod cache;
+mod sandbox;
 
 use std::rc::Rc;
 use std::cell::RefCell;
 
 use slint::ComponentHandle;
 use models::LauncherState;
-use actions::launch_app;
+use actions::{launch_app, RunningApps};
 use ui::{apps_to_slint_model, AppWindow};
 use navigation::handle_navigation;
+use worker::{LauncherRequest, LauncherResponse};
 
 use std::fs;
 use chrono::{DateTime, Local};
@@ -39,33 +45,44 @@ fn main() -> Result<(), slint::PlatformError> {
       println!("{}", w);
     }
 
+    // Start empty: loading apps and fetching the catalog both happen
+    // asynchronously on the worker thread so the window appears instantly.
     let state = Rc::new(RefCell::new(LauncherState::default()));
+    let catalog = Rc::new(RefCell::new(Vec::<install::CatalogEntry>::new()));
+    let running_apps = Rc::new(RefCell::new(RunningApps::default()));
 
-    // Set initial app lists
+    let worker = worker::spawn(state.borrow().apps_directory.clone(), state.borrow().catalog_url.clone());
+    let _ = worker.requests.send(LauncherRequest::ReloadApps);
+    let _ = worker.requests.send(LauncherRequest::FetchCatalog);
+
+    // Set initial (empty) app lists; they're refreshed as worker responses
+    // arrive via the response timer below.
     {
         let state_ref = state.borrow();
-        ui.set_featured_apps(apps_to_slint_model(&state_ref.featured_apps));
-        ui.set_installed_apps(apps_to_slint_model(&state_ref.installed_apps));
-        ui.set_store_apps(apps_to_slint_model(&state_ref.store_apps));
-        
-        // Set initial background based on first featured app
-        if let Some(first_app) = state_ref.featured_apps.first() {
-            let bg_path = &first_app.background;
-            ui.set_background_image(slint::Image::load_from_path(std::path::Path::new(&bg_path)).unwrap_or_default());
-        }
+        let running_ref = running_apps.borrow();
+        ui.set_featured_apps(apps_to_slint_model(&state_ref.featured_apps, &running_ref));
+        ui.set_installed_apps(apps_to_slint_model(&state_ref.installed_apps, &running_ref));
+        ui.set_store_apps(apps_to_slint_model(&state_ref.store_apps, &running_ref));
+        ui.set_search_results(apps_to_slint_model(&[], &running_ref));
     }
 
-    // Set up datetime timer - store it to keep it alive
+    // Set up datetime timer - store it to keep it alive. Also reaps any
+    // running app whose child has exited on its own, so the running set
+    // stays accurate without a dedicated timer.
     let ui_weak = ui.as_weak();
+    let running_apps_clone = running_apps.clone();
     let datetime_timer = slint::Timer::default();
     datetime_timer.start(slint::TimerMode::Repeated, std::time::Duration::from_secs(1), move || {
+        running_apps_clone.borrow_mut().reap_exited();
+        ui::evict_stale_icons();
+
         if let Some(ui) = ui_weak.upgrade() {
             let now: DateTime<Local> = Local::now();
             let date_str = now.format("%A, %B %d").to_string();
             let time_str = now.format("%l:%M %p").to_string();
-            
+
             println!("Setting date: {}, time: {}", date_str, time_str);
-            
+
             ui.set_current_date(date_str.into());
             ui.set_current_time(time_str.into());
         }
@@ -74,8 +91,136 @@ fn main() -> Result<(), slint::PlatformError> {
     // Wire app launch
     {
         let state_clone = state.clone();
+        let running_apps_clone = running_apps.clone();
         ui.on_launch_app(move |app_id| {
-            launch_app(&state_clone.borrow(), &app_id);
+            launch_app(&state_clone.borrow(), &mut running_apps_clone.borrow_mut(), &app_id);
+        });
+    }
+
+    // Wire task close
+    {
+        let running_apps_clone = running_apps.clone();
+        ui.on_close_app(move |app_id| {
+            actions::close_app(&mut running_apps_clone.borrow_mut(), &app_id);
+        });
+    }
+
+    // Wire app store install/uninstall. Installs are dispatched to the
+    // worker thread (network download + extraction); the result comes back
+    // through the response timer below. Uninstalling is just a local
+    // directory removal, so it stays on the UI thread.
+    {
+        let catalog_clone = catalog.clone();
+        let requests = worker.requests.clone();
+        ui.on_install_app(move |app_id| {
+            let catalog_ref = catalog_clone.borrow();
+            match catalog_ref.iter().find(|e| e.id == app_id.as_str()) {
+                Some(entry) => {
+                    let _ = requests.send(LauncherRequest::Install {
+                        entry: entry.clone(),
+                    });
+                }
+                None => eprintln!("❌ {} is not in the catalog", app_id),
+            }
+        });
+    }
+    {
+        let state_clone = state.clone();
+        let running_apps_clone = running_apps.clone();
+        let ui_weak = ui.as_weak();
+        ui.on_uninstall_app(move |app_id| {
+            let mut state_mut = state_clone.borrow_mut();
+            if let Err(e) = install::uninstall_app(&mut state_mut, &app_id) {
+                eprintln!("❌ Failed to uninstall {}: {}", app_id, e);
+            }
+            if let Some(ui) = ui_weak.upgrade() {
+                let running_ref = running_apps_clone.borrow();
+                ui.set_installed_apps(apps_to_slint_model(&state_mut.installed_apps, &running_ref));
+                ui.set_store_apps(apps_to_slint_model(&state_mut.store_apps, &running_ref));
+            }
+        });
+    }
+
+    // Drain worker responses and fold them into state + the Slint models.
+    // Polling on a short timer keeps this off the worker thread entirely
+    // while still feeling immediate to the user.
+    let worker_responses = worker.responses;
+    let response_timer = slint::Timer::default();
+    {
+        let ui_weak = ui.as_weak();
+        let state_clone = state.clone();
+        let catalog_clone = catalog.clone();
+        let running_apps_clone = running_apps.clone();
+        response_timer.start(slint::TimerMode::Repeated, std::time::Duration::from_millis(100), move || {
+            let Some(ui) = ui_weak.upgrade() else { return };
+            let mut refresh_lists = false;
+
+            while let Ok(response) = worker_responses.try_recv() {
+                let mut state_mut = state_clone.borrow_mut();
+                match response {
+                    LauncherResponse::AppsLoaded(apps) => {
+                        for app in apps {
+                            state_mut.apps.insert(app.id.clone(), app);
+                        }
+                        loader::update_app_lists(&mut state_mut);
+                        refresh_lists = true;
+                    }
+                    LauncherResponse::CatalogFetched(entries) => {
+                        install::merge_catalog(&mut state_mut, &entries);
+                        *catalog_clone.borrow_mut() = entries;
+                        refresh_lists = true;
+                    }
+                    LauncherResponse::CatalogFetchFailed(e) => {
+                        eprintln!("⚠️ Failed to fetch app catalog: {}", e);
+                    }
+                    LauncherResponse::InstallProgress { app_id, progress } => {
+                        state_mut.download_progress.insert(app_id.clone(), progress);
+                        ui.set_install_progress(app_id.into(), progress);
+                    }
+                    LauncherResponse::InstallFinished { app_id, app } => {
+                        state_mut.download_progress.remove(&app_id);
+                        state_mut.apps.insert(app_id, *app);
+                        loader::update_app_lists(&mut state_mut);
+                        refresh_lists = true;
+                    }
+                    LauncherResponse::InstallFailed { app_id, error } => {
+                        state_mut.download_progress.remove(&app_id);
+                        eprintln!("❌ Failed to install {}: {}", app_id, error);
+                    }
+                    LauncherResponse::IconDownloaded { app_id, path } => {
+                        if let Some(app) = state_mut.apps.get_mut(&app_id) {
+                            app.icon_path = Some(path);
+                        }
+                        refresh_lists = true;
+                    }
+                    LauncherResponse::IconDownloadFailed { app_id, error } => {
+                        eprintln!("⚠️ Failed to download icon for {}: {}", app_id, error);
+                    }
+                }
+            }
+
+            if refresh_lists {
+                let state_ref = state_clone.borrow();
+                let running_ref = running_apps_clone.borrow();
+                ui.set_featured_apps(apps_to_slint_model(&state_ref.featured_apps, &running_ref));
+                ui.set_installed_apps(apps_to_slint_model(&state_ref.installed_apps, &running_ref));
+                ui.set_store_apps(apps_to_slint_model(&state_ref.store_apps, &running_ref));
+            }
+        });
+    }
+
+    // Wire incremental fuzzy search across all app tabs
+    {
+        let state_clone = state.clone();
+        let running_apps_clone = running_apps.clone();
+        let ui_weak = ui.as_weak();
+        ui.on_search_changed(move |query| {
+            if let Some(ui) = ui_weak.upgrade() {
+                let state_ref = state_clone.borrow();
+                let results = search::search(&state_ref.apps, &query);
+                ui.set_search_results(apps_to_slint_model(&results, &running_apps_clone.borrow()));
+                ui.set_search_focus(0);
+            }
         });
     }
 
@@ -83,9 +228,10 @@ fn main() -> Result<(), slint::PlatformError> {
     {
         let ui_weak = ui.as_weak();
         let state_clone = state.clone();
+        let running_apps_clone = running_apps.clone();
         ui.on_navigate(move |direction| {
             if let Some(ui) = ui_weak.upgrade() {
-                handle_navigation(&ui, &direction);
+                handle_navigation(&ui, &direction, &running_apps_clone.borrow().running_ids());
                 
                 // Update background when focus changes
                 let state_ref = state_clone.borrow();
@@ -140,8 +286,9 @@ fn main() -> Result<(), slint::PlatformError> {
         });
     }
 
-    // Keep the timer alive by storing it
+    // Keep the timers alive by storing them
     std::mem::forget(datetime_timer);
+    std::mem::forget(response_timer);
 
     ui.run()
 }
\ No newline at end of file
@@ -11,6 +11,35 @@ pub struct App {
     pub installed: bool,
     pub version: String,
     pub category: String,
+    /// Requested sandbox permissions, read from the `sandbox` section of
+    /// `app.json`. `None` means deny-all: no network, no extra mounts, no
+    /// devices, matching the launcher's previous hardcoded behavior.
+    #[serde(default)]
+    pub sandbox: Option<SandboxProfile>,
+}
+
+/// Declarative permissions an app's sandbox is launched with. Deserialized
+/// from the `sandbox` section of `app.json` along with the rest of `App`, and
+/// translated into launch flags by the `sandbox` module.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SandboxProfile {
+    #[serde(default)]
+    pub network: bool,
+    #[serde(default)]
+    pub mounts: Vec<BindMount>,
+    #[serde(default)]
+    pub devices: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+}
+
+/// A single filesystem bind mount requested by an app's sandbox profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BindMount {
+    pub host_path: String,
+    pub guest_path: String,
+    #[serde(default)]
+    pub read_write: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -20,18 +49,24 @@ pub struct LauncherState {
     pub installed_apps: Vec<App>,
     pub store_apps: Vec<App>,
     pub apps_directory: String,
+    pub catalog_url: String,
+    /// Per-app download progress in `[0.0, 1.0]` while an install is in flight.
+    pub download_progress: HashMap<String, f32>,
 }
 
 impl Default for LauncherState {
+    /// Builds an empty state with no apps loaded. Actual loading happens
+    /// asynchronously on the `worker` thread via a `LauncherRequest::ReloadApps`
+    /// so startup never blocks on `fs::read_dir` or JSON parsing.
     fn default() -> Self {
-        let mut state = Self {
+        Self {
             apps: HashMap::new(),
             featured_apps: Vec::new(),
             installed_apps: Vec::new(),
             store_apps: Vec::new(),
             apps_directory: "/apps".to_string(),
-        };
-        crate::loader::load_apps(&mut state);
-        state
+            catalog_url: "https://apps.ranortv.io/catalog.json".to_string(),
+            download_progress: HashMap::new(),
+        }
     }
 }
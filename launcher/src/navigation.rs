@@ -1,7 +1,7 @@
-use slint::Model;
+use slint::{Model, ModelRc, VecModel};
 use crate::ui::AppWindow;
 
-pub fn handle_navigation(ui: &AppWindow, direction: &str) {
+pub fn handle_navigation(ui: &AppWindow, direction: &str, running_ids: &[String]) {
     let current_tab = ui.get_current_tab();
     match direction {
         "left" => {
@@ -58,8 +58,49 @@ pub fn handle_navigation(ui: &AppWindow, direction: &str) {
                 _ => {}
             }
         }
+        "search" => {
+            println!("🔍 Toggle search");
+            ui.set_search_open(!ui.get_search_open());
+        }
+        "task_switch" => {
+            println!("🗂️ Cycling task switcher");
+            if running_ids.is_empty() {
+                return;
+            }
+
+            let focus = if ui.get_task_switcher_open() {
+                (ui.get_task_switcher_focus() + 1) % running_ids.len() as i32
+            } else {
+                0
+            };
+
+            let ids: Vec<slint::SharedString> =
+                running_ids.iter().cloned().map(Into::into).collect();
+            ui.set_task_switcher_apps(ModelRc::new(VecModel::from(ids)));
+            ui.set_task_switcher_focus(focus);
+            ui.set_task_switcher_open(true);
+        }
         "select" => {
             println!("✅ Select current item");
+
+            if ui.get_task_switcher_open() {
+                let focus = ui.get_task_switcher_focus() as usize;
+                if let Some(app_id) = ui.get_task_switcher_apps().row_data(focus) {
+                    ui.invoke_launch_app(app_id);
+                }
+                ui.set_task_switcher_open(false);
+                return;
+            }
+
+            if ui.get_search_open() {
+                let focus = ui.get_search_focus() as usize;
+                let results = ui.get_search_results();
+                if let Some(app) = results.row_data(focus) {
+                    ui.invoke_launch_app(app.id);
+                }
+                return;
+            }
+
             // Launch the currently focused app
             match current_tab {
                 0 => {
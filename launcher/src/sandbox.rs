@@ -0,0 +1,187 @@
+use std::path::{Component, Path};
+use std::process::Command;
+
+use crate::models::{App, BindMount, SandboxProfile};
+
+/// Directories apps are allowed to request a bind mount under. A requested
+/// mount outside these prefixes is denied rather than granted, so an app
+/// can't use its sandbox profile to escape onto the rest of the filesystem.
+const ALLOWED_MOUNT_PREFIXES: &[&str] = &["/media", "/mnt/media", "/apps"];
+
+/// Paths apps are allowed to request a `--dev-bind` for. `--dev-bind` is a
+/// general bind mount, not restricted to actual device nodes, so it goes
+/// through the same kind of allowlist as `mounts` rather than being passed
+/// straight through.
+const ALLOWED_DEVICE_PREFIXES: &[&str] = &["/dev"];
+
+/// True if `path` is `prefix` itself or a real descendant of it — a sibling
+/// that merely shares the prefix as a string (`/mediaEvil` vs `/media`) does
+/// not count — and `path` contains no `..` component that could walk back
+/// out of it.
+fn is_within_allowed_prefix(path: &str, prefixes: &[&str]) -> bool {
+    if Path::new(path)
+        .components()
+        .any(|c| c == Component::ParentDir)
+    {
+        return false;
+    }
+
+    prefixes
+        .iter()
+        .any(|prefix| path == *prefix || path.starts_with(&format!("{prefix}/")))
+}
+
+fn is_allowed_mount(mount: &BindMount) -> bool {
+    is_within_allowed_prefix(&mount.host_path, ALLOWED_MOUNT_PREFIXES)
+}
+
+fn is_allowed_device(device: &str) -> bool {
+    is_within_allowed_prefix(device, ALLOWED_DEVICE_PREFIXES)
+}
+
+/// Builds the `bwrap` (bubblewrap) invocation for `app`, translating its
+/// sandbox profile into namespace and bind-mount flags. Apps with no
+/// `sandbox` section in `app.json` get the deny-all default: network
+/// isolated, no extra mounts, no devices — the launcher's previous
+/// hardcoded behavior.
+///
+/// `unshare(1)` has no bind-mount syntax, so it can't express the
+/// `mounts`/`devices` part of a profile; `bwrap` supports `--bind`,
+/// `--ro-bind`, and `--dev-bind` natively and is used here instead. The
+/// whole host filesystem is re-bound read-only as the sandbox root (matching
+/// what apps could already see under the old `unshare`-only sandbox, which
+/// never changed the mount namespace's root), and profile-granted mounts are
+/// layered read-write or read-only on top of that at their guest paths.
+pub fn command_for(app: &App) -> Command {
+    let profile = app.sandbox.clone().unwrap_or_default();
+
+    let mut cmd = Command::new("bwrap");
+    cmd.arg("--die-with-parent");
+    cmd.arg("--ro-bind").arg("/").arg("/");
+    cmd.arg("--dev").arg("/dev");
+    cmd.arg("--proc").arg("/proc");
+    cmd.arg("--unshare-pid");
+    if !profile.network {
+        cmd.arg("--unshare-net");
+    }
+
+    let granted_mounts: Vec<&BindMount> = profile
+        .mounts
+        .iter()
+        .filter(|mount| {
+            let allowed = is_allowed_mount(mount);
+            if !allowed {
+                eprintln!(
+                    "⚠️ {}: denying sandbox mount {} (outside the allowlist)",
+                    app.id, mount.host_path
+                );
+            }
+            allowed
+        })
+        .collect();
+
+    for mount in &granted_mounts {
+        let flag = if mount.read_write { "--bind" } else { "--ro-bind" };
+        cmd.arg(flag).arg(&mount.host_path).arg(&mount.guest_path);
+    }
+
+    let granted_devices: Vec<&String> = profile
+        .devices
+        .iter()
+        .filter(|device| {
+            let allowed = is_allowed_device(device);
+            if !allowed {
+                eprintln!(
+                    "⚠️ {}: denying sandbox device {} (outside the allowlist)",
+                    app.id, device
+                );
+            }
+            allowed
+        })
+        .collect();
+
+    for device in &granted_devices {
+        cmd.arg("--dev-bind").arg(device).arg(device);
+    }
+
+    for (key, value) in &profile.env {
+        cmd.env(key, value);
+    }
+
+    cmd.arg(&app.executable_path);
+
+    log_profile(app, &profile, granted_mounts.len(), granted_devices.len());
+    cmd
+}
+
+/// Logs the effective profile at launch so users can audit what each app
+/// was actually granted, after allowlist filtering.
+fn log_profile(app: &App, profile: &SandboxProfile, granted_mounts: usize, granted_devices: usize) {
+    println!(
+        "🔒 Sandbox profile for {}: network={} mounts={}/{} devices={}/{} env_vars={}",
+        app.id,
+        profile.network,
+        granted_mounts,
+        profile.mounts.len(),
+        granted_devices,
+        profile.devices.len(),
+        profile.env.len(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mount(host_path: &str) -> BindMount {
+        BindMount {
+            host_path: host_path.to_string(),
+            guest_path: host_path.to_string(),
+            read_write: false,
+        }
+    }
+
+    #[test]
+    fn allows_mounts_under_allowlisted_prefixes() {
+        assert!(is_allowed_mount(&mount("/media/movies")));
+        assert!(is_allowed_mount(&mount("/mnt/media/shows")));
+        assert!(is_allowed_mount(&mount("/apps/some-app/data")));
+    }
+
+    #[test]
+    fn denies_mounts_outside_the_allowlist() {
+        assert!(!is_allowed_mount(&mount("/etc")));
+        assert!(!is_allowed_mount(&mount("/home/user/.ssh")));
+        assert!(!is_allowed_mount(&mount("/")));
+    }
+
+    #[test]
+    fn denies_prefix_sibling_directories() {
+        // These share a string prefix with an allowed entry but are not
+        // descendants of it, so a naive `starts_with` would wrongly admit
+        // them.
+        assert!(!is_allowed_mount(&mount("/mediaEvil/secret")));
+        assert!(!is_allowed_mount(&mount("/mnt/mediadrive")));
+        assert!(!is_allowed_mount(&mount("/appsbackup")));
+    }
+
+    #[test]
+    fn denies_mounts_with_a_parent_dir_component() {
+        assert!(!is_allowed_mount(&mount("/media/../etc")));
+    }
+
+    #[test]
+    fn allows_devices_under_dev() {
+        assert!(is_allowed_device("/dev/video0"));
+        assert!(is_allowed_device("/dev/dri/renderD128"));
+    }
+
+    #[test]
+    fn denies_devices_outside_allowlist() {
+        assert!(!is_allowed_device("/etc"));
+        assert!(!is_allowed_device("/home/user/.ssh"));
+        assert!(!is_allowed_device("/"));
+        // Shares a string prefix with /dev but isn't a descendant of it.
+        assert!(!is_allowed_device("/devious"));
+    }
+}
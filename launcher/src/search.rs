@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use crate::models::App;
+
+/// Ranks every app whose name fuzzily matches `query` (a subsequence match,
+/// like a typical TUI launcher) across featured/installed/store alike,
+/// since `apps` is the full unfiltered index.
+pub fn search(apps: &HashMap<String, App>, query: &str) -> Vec<App> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut scored: Vec<(i32, App)> = apps
+        .values()
+        .filter_map(|app| score(query, &app.name).map(|s| (s, app.clone())))
+        .collect();
+
+    // Higher score first, then shorter name, then app id, so results are
+    // deterministic regardless of `HashMap` iteration order.
+    scored.sort_by(|(score_a, app_a), (score_b, app_b)| {
+        score_b
+            .cmp(score_a)
+            .then_with(|| app_a.name.len().cmp(&app_b.name.len()))
+            .then_with(|| app_a.id.cmp(&app_b.id))
+    });
+
+    scored.into_iter().map(|(_, app)| app).collect()
+}
+
+/// Scores `target` against `query` as a case-insensitive subsequence match.
+/// Returns `None` if `query` isn't a subsequence of `target` at all.
+///
+/// An exact substring match is scored highest (weighted by how early it
+/// starts), followed by a subsequence match with bonuses for consecutive
+/// characters and matches that land on a word boundary.
+fn score(query: &str, target: &str) -> Option<i32> {
+    let query = query.to_lowercase();
+    let target = target.to_lowercase();
+
+    if let Some(byte_pos) = target.find(&query) {
+        // `find` returns a byte offset; `is_word_boundary` and the scoring
+        // below both index by character, so convert before using it for
+        // either — otherwise both desync for any multi-byte app name.
+        let pos = target[..byte_pos].chars().count();
+        let mut s = 1_000 - pos as i32;
+        if pos == 0 || is_word_boundary(&target, pos) {
+            s += 500;
+        }
+        return Some(s);
+    }
+
+    let target_chars: Vec<char> = target.chars().collect();
+    let query_chars: Vec<char> = query.chars().collect();
+
+    let mut score = 0;
+    let mut query_index = 0;
+    let mut consecutive = 0;
+    let mut prev_match: Option<usize> = None;
+
+    for (target_index, &c) in target_chars.iter().enumerate() {
+        if query_index >= query_chars.len() {
+            break;
+        }
+        if c != query_chars[query_index] {
+            continue;
+        }
+
+        score += 10;
+
+        if prev_match == Some(target_index.wrapping_sub(1)) {
+            consecutive += 1;
+            score += 5 * consecutive;
+        } else {
+            consecutive = 0;
+        }
+
+        if target_index == 0 || is_word_boundary(&target, target_index) {
+            score += 15;
+        }
+
+        prev_match = Some(target_index);
+        query_index += 1;
+    }
+
+    if query_index == query_chars.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+fn is_word_boundary(s: &str, byte_or_char_index: usize) -> bool {
+    s.chars()
+        .nth(byte_or_char_index.saturating_sub(1))
+        .map(|c| matches!(c, ' ' | '-' | '_'))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring_match_scores_above_subsequence_only_match() {
+        let substring = score("mov", "Movies").unwrap();
+        let subsequence = score("mis", "Movies").unwrap();
+        assert!(substring > subsequence);
+    }
+
+    #[test]
+    fn non_subsequence_does_not_match() {
+        assert_eq!(score("zz", "Movies"), None);
+    }
+
+    #[test]
+    fn earlier_substring_match_scores_higher() {
+        let early = score("mo", "Movies").unwrap();
+        let late = score("mo", "Casa Mole").unwrap();
+        assert!(early > late);
+    }
+
+    #[test]
+    fn word_boundary_match_is_scored_higher_than_mid_word() {
+        let boundary = score("m", "Casa Movies").unwrap();
+        let mid_word = score("o", "Casa Movies").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn multibyte_substring_match_does_not_panic_and_scores_consistently() {
+        // "Músic" has a multi-byte character before the match position;
+        // a byte-index/char-index mixup here would either panic via an
+        // out-of-bounds char boundary or silently miscompute the score.
+        let with_multibyte_prefix = score("ic", "Músic").unwrap();
+        let without = score("ic", "Music").unwrap();
+        assert_eq!(with_multibyte_prefix, without);
+    }
+}
@@ -1,9 +1,22 @@
 use slint::{ModelRc, VecModel, Image};
+use std::cell::RefCell;
 use std::path::Path;
+use crate::actions::RunningApps;
+use crate::cache::IconCache;
 use crate::models::App;
 slint::include_modules!();
 
-pub fn apps_to_slint_model(apps: &[App]) -> ModelRc<AppItem> {
+thread_local! {
+    static ICON_CACHE: RefCell<IconCache> = RefCell::new(IconCache::default());
+}
+
+/// Drops any cached icon whose backing file has since disappeared. Called
+/// periodically from `main`'s datetime timer.
+pub fn evict_stale_icons() {
+    ICON_CACHE.with(|cache| cache.borrow_mut().evict_missing());
+}
+
+pub fn apps_to_slint_model(apps: &[App], running: &RunningApps) -> ModelRc<AppItem> {
     let items: Vec<AppItem> = apps
         .iter()
         .map(|app| AppItem {
@@ -12,6 +25,7 @@ pub fn apps_to_slint_model(apps: &[App]) -> ModelRc<AppItem> {
             description: app.description.clone().into(),
             icon: load_icon(&app.icon.clone().unwrap_or_default()),
             category: app.category.clone().into(),
+            running: running.is_running(&app.id),
         })
         .collect();
     ModelRc::new(VecModel::from(items))
@@ -22,7 +36,33 @@ fn load_icon(icon_path: &str) -> Image {
         return load_default_icon();
     }
 
-    // Try multiple possible locations
+    let candidates = resolve_icon_candidates(icon_path);
+    if candidates.is_empty() {
+        eprintln!("Could not load icon: {}", icon_path);
+        return load_default_icon();
+    }
+
+    ICON_CACHE
+        .with(|cache| {
+            let mut cache = cache.borrow_mut();
+            // Keep trying candidates until one actually decodes: an earlier
+            // candidate might exist on disk but be corrupt, zero-byte, or an
+            // unsupported format, and falling back to the default icon over
+            // that would skip real candidates further down the list.
+            candidates
+                .iter()
+                .find_map(|path| cache.get_or_decode(path, |p| load_image_from_path(p).ok()))
+        })
+        .unwrap_or_else(|| {
+            eprintln!("Could not load icon: {}", icon_path);
+            load_default_icon()
+        })
+}
+
+/// Returns every candidate location for `icon_path` that exists on disk, in
+/// priority order, so `load_icon` can keep trying candidates until one
+/// actually decodes rather than committing to the first that merely exists.
+fn resolve_icon_candidates(icon_path: &str) -> Vec<String> {
     let mut search_paths = vec![
         icon_path.to_string(),                           // Exact path as given
         format!("assets/{}", icon_path),                 // Relative to assets
@@ -34,15 +74,7 @@ fn load_icon(icon_path: &str) -> Image {
         search_paths.push(user_icons.join(icon_path).to_string_lossy().to_string());
     }
 
-    for path in &search_paths {
-        if let Ok(image) = load_image_from_path(path) {
-            return image;
-        }
-    }
-
-    // If nothing found, return default
-    eprintln!("Could not load icon: {}", icon_path);
-    load_default_icon()
+    search_paths.into_iter().filter(|path| Path::new(path).exists()).collect()
 }
 
 fn load_image_from_path(path: &str) -> Result<Image, Box<dyn std::error::Error>> {
@@ -59,16 +91,7 @@ fn load_image_from_path(path: &str) -> Result<Image, Box<dyn std::error::Error>>
 }
 
 fn get_user_icons_dir() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-    // Use platform-specific app data directory
-    let app_data = if cfg!(target_os = "windows") {
-        std::env::var("APPDATA")?
-    } else if cfg!(target_os = "macos") {
-        format!("{}/Library/Application Support", std::env::var("HOME")?)
-    } else {
-        format!("{}/.local/share", std::env::var("HOME")?)
-    };
-    
-    Ok(Path::new(&app_data).join("YourAppName").join("icons"))
+    Ok(crate::cache::user_data_dir()?.join("icons"))
 }
 
 fn load_default_icon() -> Image {
@@ -0,0 +1,129 @@
+use std::io::Read;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use crate::cache::AppCache;
+use crate::install::{self, CatalogEntry};
+use crate::models::{App, LauncherState};
+
+/// Work the UI thread hands off to the background worker. The worker owns
+/// every slow resource (disk scans, network calls) so the UI thread only
+/// ever sends a request and later receives a `LauncherResponse`.
+pub enum LauncherRequest {
+    ReloadApps,
+    FetchCatalog,
+    DownloadIcon { app_id: String, url: String },
+    Install { entry: CatalogEntry },
+}
+
+pub enum LauncherResponse {
+    AppsLoaded(Vec<App>),
+    CatalogFetched(Vec<CatalogEntry>),
+    CatalogFetchFailed(String),
+    IconDownloaded { app_id: String, path: String },
+    IconDownloadFailed { app_id: String, error: String },
+    InstallProgress { app_id: String, progress: f32 },
+    InstallFinished { app_id: String, app: Box<App> },
+    InstallFailed { app_id: String, error: String },
+}
+
+/// A handle to the background worker thread: send `LauncherRequest`s in,
+/// drain `LauncherResponse`s out. The thread itself owns the apps directory
+/// and catalog URL, so the UI thread never blocks on `fs::read_dir` or a
+/// network round-trip.
+pub struct Worker {
+    pub requests: Sender<LauncherRequest>,
+    pub responses: Receiver<LauncherResponse>,
+}
+
+pub fn spawn(apps_directory: String, catalog_url: String) -> Worker {
+    let (request_tx, request_rx) = mpsc::channel::<LauncherRequest>();
+    let (response_tx, response_rx) = mpsc::channel::<LauncherResponse>();
+
+    thread::spawn(move || run(apps_directory, catalog_url, request_rx, response_tx));
+
+    Worker {
+        requests: request_tx,
+        responses: response_rx,
+    }
+}
+
+fn run(
+    apps_directory: String,
+    catalog_url: String,
+    requests: Receiver<LauncherRequest>,
+    responses: Sender<LauncherResponse>,
+) {
+    // Loaded once and persisted back after every reload, so a cold start
+    // skips re-parsing `app.json` for directories that haven't changed.
+    let mut app_cache = AppCache::load();
+
+    for request in requests {
+        match request {
+            LauncherRequest::ReloadApps => {
+                let mut scratch = LauncherState {
+                    apps_directory: apps_directory.clone(),
+                    ..LauncherState::default()
+                };
+                crate::loader::load_apps(&mut scratch, &mut app_cache);
+                app_cache.save();
+                let _ = responses.send(LauncherResponse::AppsLoaded(
+                    scratch.apps.into_values().collect(),
+                ));
+            }
+            LauncherRequest::FetchCatalog => {
+                let result = install::fetch_catalog(&catalog_url);
+                let response = match result {
+                    Ok(entries) => LauncherResponse::CatalogFetched(entries),
+                    Err(e) => LauncherResponse::CatalogFetchFailed(e.to_string()),
+                };
+                let _ = responses.send(response);
+            }
+            LauncherRequest::DownloadIcon { app_id, url } => {
+                let response = match download_icon(&apps_directory, &app_id, &url) {
+                    Ok(path) => LauncherResponse::IconDownloaded { app_id, path },
+                    Err(error) => LauncherResponse::IconDownloadFailed { app_id, error },
+                };
+                let _ = responses.send(response);
+            }
+            LauncherRequest::Install { entry } => {
+                let app_id = entry.id.clone();
+                let progress_tx = responses.clone();
+                let progress_app_id = app_id.clone();
+                let result = install::download_and_install(&apps_directory, &entry, move |progress| {
+                    let _ = progress_tx.send(LauncherResponse::InstallProgress {
+                        app_id: progress_app_id.clone(),
+                        progress,
+                    });
+                });
+                let response = match result {
+                    Ok(app) => LauncherResponse::InstallFinished {
+                        app_id,
+                        app: Box::new(app),
+                    },
+                    Err(e) => LauncherResponse::InstallFailed {
+                        app_id,
+                        error: e.to_string(),
+                    },
+                };
+                let _ = responses.send(response);
+            }
+        }
+    }
+}
+
+fn download_icon(apps_directory: &str, app_id: &str, url: &str) -> Result<String, String> {
+    let mut bytes = Vec::new();
+    ureq::get(url)
+        .call()
+        .map_err(|e| e.to_string())?
+        .into_reader()
+        .read_to_end(&mut bytes)
+        .map_err(|e| e.to_string())?;
+
+    let path = std::path::PathBuf::from(apps_directory)
+        .join(app_id)
+        .join("icon.png");
+    std::fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().to_string())
+}